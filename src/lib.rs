@@ -2,8 +2,23 @@
 //!
 //! [1]: https://en.wikipedia.org/wiki/Complex_number
 
+use std::error::Error;
+use std::fmt;
 use std::fmt::Debug;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// An error returned when parsing a complex number from a string fails.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParseComplexError;
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("failed to parse a complex number")
+    }
+}
+
+impl Error for ParseComplexError {
+}
 
 /// A number.
 pub trait Number: Add<Output=Self> +
@@ -12,10 +27,47 @@ pub trait Number: Add<Output=Self> +
                   Neg<Output=Self> +
                   Sub<Output=Self> +
                   Copy + Debug + PartialEq {
+    /// Return zero.
+    fn zero() -> Self;
+
+    /// Return one.
+    fn one() -> Self;
+
+    /// Check if the number is zero.
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
 }
 
 /// A real number.
-pub trait Real: Number {
+pub trait Real: Number + PartialOrd {
+    /// Compute the sine.
+    fn sin(self) -> Self;
+
+    /// Compute the cosine.
+    fn cos(self) -> Self;
+
+    /// Compute the square root.
+    fn sqrt(self) -> Self;
+
+    /// Compute the natural logarithm.
+    fn ln(self) -> Self;
+
+    /// Compute the exponential.
+    fn exp(self) -> Self;
+
+    /// Compute the four-quadrant arctangent of `self` and `other`.
+    fn atan2(self, other: Self) -> Self;
+
+    /// Compute `sqrt(self^2 + other^2)` without undue overflow or underflow.
+    fn hypot(self, other: Self) -> Self;
+
+    /// Round to the nearest integer.
+    fn round(self) -> Self;
+
+    /// Parse a value from a string in a given radix.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseComplexError>;
 }
 
 /// A complex number.
@@ -43,238 +95,725 @@ pub trait Complex: Number {
     fn conj(&self) -> Self {
         Complex::new(self.re(), -self.im())
     }
+
+    /// Return the imaginary unit.
+    #[inline(always)]
+    fn i() -> Self {
+        Complex::new(Self::Real::zero(), Self::Real::one())
+    }
+
+    /// Compute the square root.
+    fn sqrt(&self) -> Self;
+
+    /// Compute the exponential.
+    fn exp(&self) -> Self;
+
+    /// Compute the natural logarithm.
+    fn ln(&self) -> Self;
+
+    /// Compute the sine.
+    fn sin(&self) -> Self;
+
+    /// Compute the cosine.
+    fn cos(&self) -> Self;
+
+    /// Compute the arcsine.
+    fn asin(&self) -> Self;
+
+    /// Compute the arccosine.
+    fn acos(&self) -> Self;
+
+    /// Raise to an integer power.
+    fn powi(&self, n: i32) -> Self;
+
+    /// Raise to a complex power.
+    fn powc(&self, other: Self) -> Self;
+
+    /// Create a complex number from a magnitude and an angle.
+    #[inline(always)]
+    fn from_polar(r: Self::Real, theta: Self::Real) -> Self {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// Compute the squared norm, i.e., `re^2 + im^2`.
+    #[inline(always)]
+    fn norm_sqr(&self) -> Self::Real {
+        self.re() * self.re() + self.im() * self.im()
+    }
+
+    /// Compute the norm, i.e., the magnitude.
+    #[inline(always)]
+    fn norm(&self) -> Self::Real {
+        self.re().hypot(self.im())
+    }
+
+    /// Compute the argument, i.e., the angle.
+    #[inline(always)]
+    fn arg(&self) -> Self::Real {
+        self.im().atan2(self.re())
+    }
+
+    /// Convert into a magnitude and an angle.
+    #[inline(always)]
+    fn to_polar(&self) -> (Self::Real, Self::Real) {
+        (self.norm(), self.arg())
+    }
+
+    /// Parse a complex number from a string such as `"1+2i"` or `"-3i"` in a
+    /// given radix.
+    ///
+    /// The real and imaginary parts are split on the last `+`/`-` in the
+    /// string, so scientific notation in the imaginary part (e.g.
+    /// `"1+2e-3i"`) is not supported; the exponent's sign is mistaken for the
+    /// separator.
+    ///
+    /// Each part is parsed with `Real::from_str_radix`; the implementations
+    /// for `f32` and `f64` accept only radix 10 and return an error for any
+    /// other radix.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseComplexError>;
 }
 
+/// A complex number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComplexNumber<T: Real>(pub T, pub T);
+
 /// A complex number with 32-bit parts.
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct c32(pub f32, pub f32);
+pub type c32 = ComplexNumber<f32>;
 
 /// A complex number with 64-bit parts.
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct c64(pub f64, pub f64);
-
-macro_rules! implement(
-    ($complex:ident, $real:ty) => (
-        impl Number for $complex {
-        }
+pub type c64 = ComplexNumber<f64>;
 
+macro_rules! implement_real(
+    ($real:ident) => (
         impl Number for $real {
-        }
+            #[inline(always)]
+            fn zero() -> Self {
+                0.0
+            }
 
-        impl Real for $real {
+            #[inline(always)]
+            fn one() -> Self {
+                1.0
+            }
         }
 
-        impl Complex for $complex {
-            type Real = $real;
-
+        impl Real for $real {
             #[inline(always)]
-            fn new(re: Self::Real, im: Self::Real) -> $complex {
-                $complex(re, im)
+            fn sin(self) -> Self {
+                self.sin()
             }
 
             #[inline(always)]
-            fn re(&self) -> Self::Real {
-                self.0
+            fn cos(self) -> Self {
+                self.cos()
             }
 
             #[inline(always)]
-            fn re_mut(&mut self) -> &mut Self::Real {
-                &mut self.0
+            fn sqrt(self) -> Self {
+                self.sqrt()
             }
 
             #[inline(always)]
-            fn im(&self) -> Self::Real {
-                self.1
+            fn ln(self) -> Self {
+                self.ln()
             }
 
             #[inline(always)]
-            fn im_mut(&mut self) -> &mut Self::Real {
-                &mut self.1
+            fn exp(self) -> Self {
+                self.exp()
             }
-        }
-
-        impl Add for $complex {
-            type Output = Self;
 
             #[inline(always)]
-            fn add(self, rhs: Self) -> Self::Output {
-                Complex::new(self.re() + rhs.re(), self.im() + rhs.im())
+            fn atan2(self, other: Self) -> Self {
+                self.atan2(other)
             }
-        }
 
-        impl Add<$real> for $complex {
-            type Output = Self;
+            #[inline(always)]
+            fn hypot(self, other: Self) -> Self {
+                self.hypot(other)
+            }
 
             #[inline(always)]
-            fn add(self, rhs: $real) -> Self::Output {
-                Complex::new(self.re() + rhs, self.im())
+            fn round(self) -> Self {
+                self.round()
+            }
+
+            // Only radix 10 is supported; any other radix is rejected.
+            fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseComplexError> {
+                if radix != 10 {
+                    return Err(ParseComplexError);
+                }
+                src.parse().map_err(|_| ParseComplexError)
             }
         }
 
-        impl Add<$complex> for $real {
-            type Output = $complex;
+        // The orphan rules require these to be implemented per concrete real
+        // type rather than generically over `T: Real`.
+        impl Add<ComplexNumber<$real>> for $real {
+            type Output = ComplexNumber<$real>;
 
             #[inline(always)]
-            fn add(self, rhs: $complex) -> Self::Output {
+            fn add(self, rhs: ComplexNumber<$real>) -> Self::Output {
                 Complex::new(self + rhs.re(), rhs.im())
             }
         }
 
-        impl Div for $complex {
-            type Output = Self;
+        impl Div<ComplexNumber<$real>> for $real {
+            type Output = ComplexNumber<$real>;
 
             #[inline(always)]
-            fn div(self, rhs: Self) -> Self::Output {
-                let denominator = rhs.re() * rhs.re() + rhs.im() * rhs.im();
-                Complex::new((self.re() * rhs.re() + self.im() * rhs.im()) / denominator,
-                             (self.im() * rhs.re() - self.re() * rhs.im()) / denominator)
+            fn div(self, rhs: ComplexNumber<$real>) -> Self::Output {
+                let denominator = rhs.norm_sqr();
+                Complex::new((self * rhs.re()) / denominator, (-self * rhs.im()) / denominator)
             }
         }
 
-        impl Div<$real> for $complex {
-            type Output = Self;
+        impl Mul<ComplexNumber<$real>> for $real {
+            type Output = ComplexNumber<$real>;
 
             #[inline(always)]
-            fn div(self, rhs: $real) -> Self::Output {
-                Complex::new(self.re() / rhs, self.im() / rhs)
+            fn mul(self, rhs: ComplexNumber<$real>) -> Self::Output {
+                Complex::new(self * rhs.re(), self * rhs.im())
             }
         }
 
-        impl Div<$complex> for $real {
-            type Output = $complex;
+        impl Sub<ComplexNumber<$real>> for $real {
+            type Output = ComplexNumber<$real>;
 
             #[inline(always)]
-            fn div(self, rhs: $complex) -> Self::Output {
-                let denominator = rhs.re() * rhs.re() + rhs.im() * rhs.im();
-                Complex::new((self * rhs.re()) / denominator, (-self * rhs.im()) / denominator)
+            fn sub(self, rhs: ComplexNumber<$real>) -> Self::Output {
+                Complex::new(self - rhs.re(), -rhs.im())
             }
         }
 
-        impl Mul for $complex {
-            type Output = Self;
+        impl Rem<ComplexNumber<$real>> for $real {
+            type Output = ComplexNumber<$real>;
 
             #[inline(always)]
-            fn mul(self, rhs: Self) -> Self::Output {
-                Complex::new(self.re() * rhs.re() - self.im() * rhs.im(),
-                             self.im() * rhs.re() + self.re() * rhs.im())
+            fn rem(self, rhs: ComplexNumber<$real>) -> Self::Output {
+                let quotient = self / rhs;
+                let quotient = ComplexNumber(quotient.re().round(), quotient.im().round());
+                self - quotient * rhs
             }
         }
+    );
+);
 
-        impl Mul<$real> for $complex {
-            type Output = Self;
+implement_real!(f32);
+implement_real!(f64);
 
-            #[inline(always)]
-            fn mul(self, rhs: $real) -> Self::Output {
-                Complex::new(self.re() * rhs, self.im() * rhs)
-            }
+impl<T: Real> Number for ComplexNumber<T> {
+    #[inline(always)]
+    fn zero() -> Self {
+        ComplexNumber(T::zero(), T::zero())
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        ComplexNumber(T::one(), T::zero())
+    }
+}
+
+impl<T: Real> Complex for ComplexNumber<T> {
+    type Real = T;
+
+    #[inline(always)]
+    fn new(re: T, im: T) -> Self {
+        ComplexNumber(re, im)
+    }
+
+    #[inline(always)]
+    fn re(&self) -> T {
+        self.0
+    }
+
+    #[inline(always)]
+    fn re_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    #[inline(always)]
+    fn im(&self) -> T {
+        self.1
+    }
+
+    #[inline(always)]
+    fn im_mut(&mut self) -> &mut T {
+        &mut self.1
+    }
+
+    fn sqrt(&self) -> Self {
+        let one = T::one();
+        let two = one + one;
+        let r = Complex::norm(self);
+        let re = ((r + self.re()) / two).sqrt();
+        let im = ((r - self.re()) / two).sqrt();
+        if self.im() < T::zero() {
+            Complex::new(re, -im)
+        } else {
+            Complex::new(re, im)
         }
+    }
 
-        impl Mul<$complex> for $real {
-            type Output = $complex;
+    fn exp(&self) -> Self {
+        let r = self.re().exp();
+        Complex::new(r * self.im().cos(), r * self.im().sin())
+    }
 
-            #[inline(always)]
-            fn mul(self, rhs: $complex) -> Self::Output {
-                Complex::new(self * rhs.re(), self * rhs.im())
+    fn ln(&self) -> Self {
+        Complex::new(Complex::norm(self).ln(), Complex::arg(self))
+    }
+
+    fn sin(&self) -> Self {
+        let two = T::one() + T::one();
+        let i = Self::i();
+        let d = Complex::exp(&(i * *self)) - Complex::exp(&(-i * *self));
+        Complex::new(d.im() / two, -d.re() / two)
+    }
+
+    fn cos(&self) -> Self {
+        let two = T::one() + T::one();
+        let i = Self::i();
+        let s = Complex::exp(&(i * *self)) + Complex::exp(&(-i * *self));
+        Complex::new(s.re() / two, s.im() / two)
+    }
+
+    fn asin(&self) -> Self {
+        let i = Self::i();
+        let square = *self * *self;
+        let w = Complex::sqrt(&(Self::one() - square));
+        let l = Complex::ln(&(i * *self + w));
+        Complex::new(l.im(), -l.re())
+    }
+
+    fn acos(&self) -> Self {
+        let i = Self::i();
+        let square = *self * *self;
+        let w = Complex::sqrt(&(Self::one() - square));
+        let l = Complex::ln(&(*self + i * w));
+        Complex::new(l.im(), -l.re())
+    }
+
+    fn powi(&self, n: i32) -> Self {
+        if n == 0 {
+            return Self::one();
+        }
+        let negative = n < 0;
+        let mut n = n.unsigned_abs();
+        let mut base = *self;
+        let mut result = Self::one();
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= base;
             }
+            base = base * base;
+            n >>= 1;
         }
+        if negative { Self::one() / result } else { result }
+    }
 
-        impl Neg for $complex {
-            type Output = Self;
+    fn powc(&self, other: Self) -> Self {
+        Complex::exp(&(other * Complex::ln(self)))
+    }
 
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseComplexError> {
+        let src = src.trim();
+        if src.is_empty() {
+            return Err(ParseComplexError);
+        }
+        let body = match src.strip_suffix('i') {
+            Some(body) => body,
+            None => {
+                let re = Real::from_str_radix(src, radix)?;
+                return Ok(Complex::new(re, T::zero()));
+            },
+        };
+        let split = body.char_indices().skip(1)
+                         .filter(|&(_, c)| c == '+' || c == '-')
+                         .last();
+        let (re_part, im_part) = match split {
+            Some((index, _)) => (&body[..index], &body[index..]),
+            None => ("", body),
+        };
+        let re = if re_part.is_empty() {
+            T::zero()
+        } else {
+            Real::from_str_radix(re_part, radix)?
+        };
+        let im = match im_part {
+            "" | "+" => T::one(),
+            "-" => -T::one(),
+            _ => Real::from_str_radix(im_part, radix)?,
+        };
+        Ok(Complex::new(re, im))
+    }
+}
+
+impl<T: Real> Add for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Complex::new(self.re() + rhs.re(), self.im() + rhs.im())
+    }
+}
+
+impl<T: Real> Add<T> for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: T) -> Self::Output {
+        Complex::new(self.re() + rhs, self.im())
+    }
+}
+
+impl<T: Real> Div for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let denominator = rhs.norm_sqr();
+        Complex::new((self.re() * rhs.re() + self.im() * rhs.im()) / denominator,
+                     (self.im() * rhs.re() - self.re() * rhs.im()) / denominator)
+    }
+}
+
+impl<T: Real> Div<T> for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: T) -> Self::Output {
+        Complex::new(self.re() / rhs, self.im() / rhs)
+    }
+}
+
+impl<T: Real> Mul for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Complex::new(self.re() * rhs.re() - self.im() * rhs.im(),
+                     self.im() * rhs.re() + self.re() * rhs.im())
+    }
+}
+
+impl<T: Real> Mul<T> for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: T) -> Self::Output {
+        Complex::new(self.re() * rhs, self.im() * rhs)
+    }
+}
+
+impl<T: Real> Rem for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quotient = self / rhs;
+        let quotient = ComplexNumber(quotient.re().round(), quotient.im().round());
+        self - quotient * rhs
+    }
+}
+
+impl<T: Real> Rem<T> for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: T) -> Self::Output {
+        let quotient = self / rhs;
+        let quotient = ComplexNumber(quotient.re().round(), quotient.im().round());
+        self - quotient * rhs
+    }
+}
+
+impl<T: Real> Neg for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Complex::new(-self.re(), -self.im())
+    }
+}
+
+impl<T: Real> Sub for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Complex::new(self.re() - rhs.re(), self.im() - rhs.im())
+    }
+}
+
+impl<T: Real> Sub<T> for ComplexNumber<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: T) -> Self::Output {
+        Complex::new(self.re() - rhs, self.im())
+    }
+}
+
+macro_rules! implement_ops(
+    ($trait:ident, $method:ident, $trait_assign:ident, $method_assign:ident) => (
+        impl<T: Real> $trait_assign for ComplexNumber<T> {
             #[inline(always)]
-            fn neg(self) -> Self::Output {
-                Complex::new(-self.re(), -self.im())
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = $trait::$method(*self, rhs);
             }
         }
 
-        impl Sub for $complex {
-            type Output = Self;
-
+        impl<T: Real> $trait_assign<T> for ComplexNumber<T> {
             #[inline(always)]
-            fn sub(self, rhs: Self) -> Self::Output {
-                Complex::new(self.re() - rhs.re(), self.im() - rhs.im())
+            fn $method_assign(&mut self, rhs: T) {
+                *self = $trait::$method(*self, rhs);
             }
         }
 
-        impl Sub<$real> for $complex {
-            type Output = Self;
+        impl<'r, T: Real> $trait<&'r ComplexNumber<T>> for ComplexNumber<T> {
+            type Output = ComplexNumber<T>;
 
             #[inline(always)]
-            fn sub(self, rhs: $real) -> Self::Output {
-                Complex::new(self.re() - rhs, self.im())
+            fn $method(self, rhs: &'r ComplexNumber<T>) -> Self::Output {
+                $trait::$method(self, *rhs)
             }
         }
 
-        impl Sub<$complex> for $real {
-            type Output = $complex;
+        impl<'l, 'r, T: Real> $trait<&'r ComplexNumber<T>> for &'l ComplexNumber<T> {
+            type Output = ComplexNumber<T>;
 
             #[inline(always)]
-            fn sub(self, rhs: $complex) -> Self::Output {
-                Complex::new(self - rhs.re(), -rhs.im())
+            fn $method(self, rhs: &'r ComplexNumber<T>) -> Self::Output {
+                $trait::$method(*self, *rhs)
             }
         }
     );
 );
 
-implement!(c32, f32);
-implement!(c64, f64);
+implement_ops!(Add, add, AddAssign, add_assign);
+implement_ops!(Sub, sub, SubAssign, sub_assign);
+implement_ops!(Mul, mul, MulAssign, mul_assign);
+implement_ops!(Div, div, DivAssign, div_assign);
 
 #[cfg(test)]
 mod tests {
-    use {Complex, c64};
+    use {Complex, Number, ParseComplexError, c64};
 
     #[test]
     fn re_mut() {
-        let mut number = c64(69.0, 0.0);
+        let mut number = c64::new(69.0, 0.0);
         *number.re_mut() = 42.0;
-        assert_eq!(number, c64(42.0, 0.0));
+        assert_eq!(number, c64::new(42.0, 0.0));
     }
 
     #[test]
     fn im_mut() {
-        let mut number = c64(0.0, 69.0);
+        let mut number = c64::new(0.0, 69.0);
         *number.im_mut() = 42.0;
-        assert_eq!(number, c64(0.0, 42.0));
+        assert_eq!(number, c64::new(0.0, 42.0));
     }
 
     #[test]
     fn conj() {
-        assert_eq!(c64(42.0, 69.0).conj(), c64(42.0, -69.0));
+        assert_eq!(c64::new(42.0, 69.0).conj(), c64::new(42.0, -69.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn add() {
-        assert_eq!(c64(-4.0, 7.0) + c64(5.0, -10.0), c64(1.0, -3.0));
-        assert_eq!(c64(-4.0, 7.0) + 5.0, c64(1.0, 7.0));
-        assert_eq!(5.0 + c64(-4.0, 7.0), c64(1.0, 7.0));
+        assert_eq!(c64::new(-4.0, 7.0) + c64::new(5.0, -10.0), c64::new(1.0, -3.0));
+        assert_eq!(c64::new(-4.0, 7.0) + 5.0, c64::new(1.0, 7.0));
+        assert_eq!(5.0 + c64::new(-4.0, 7.0), c64::new(1.0, 7.0));
+        assert_eq!(c64::new(-4.0, 7.0) + &c64::new(5.0, -10.0), c64::new(1.0, -3.0));
+        assert_eq!(&c64::new(-4.0, 7.0) + &c64::new(5.0, -10.0), c64::new(1.0, -3.0));
     }
 
     #[test]
+    fn add_assign() {
+        let mut number = c64::new(-4.0, 7.0);
+        number += c64::new(5.0, -10.0);
+        assert_eq!(number, c64::new(1.0, -3.0));
+        number += 5.0;
+        assert_eq!(number, c64::new(6.0, -3.0));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
     fn div() {
-        assert_eq!(c64(3.0, -1.0) / c64(2.0, 7.0), c64(-1.0 / 53.0, -23.0 / 53.0));
-        assert_eq!(c64(3.0, -1.0) / 2.0, c64(1.5, -0.5));
-        assert_eq!(2.0 / c64(3.0, -1.0), c64(0.6, 0.2));
+        assert_eq!(c64::new(3.0, -1.0) / c64::new(2.0, 7.0), c64::new(-1.0 / 53.0, -23.0 / 53.0));
+        assert_eq!(c64::new(3.0, -1.0) / 2.0, c64::new(1.5, -0.5));
+        assert_eq!(2.0 / c64::new(3.0, -1.0), c64::new(0.6, 0.2));
+        assert_eq!(c64::new(3.0, -1.0) / &c64::new(2.0, 7.0), c64::new(-1.0 / 53.0, -23.0 / 53.0));
+        assert_eq!(&c64::new(3.0, -1.0) / &c64::new(2.0, 7.0), c64::new(-1.0 / 53.0, -23.0 / 53.0));
     }
 
     #[test]
+    fn div_assign() {
+        let mut number = c64::new(3.0, -1.0);
+        number /= c64::new(2.0, 7.0);
+        assert_eq!(number, c64::new(-1.0 / 53.0, -23.0 / 53.0));
+        let mut number = c64::new(3.0, -1.0);
+        number /= 2.0;
+        assert_eq!(number, c64::new(1.5, -0.5));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
     fn mul() {
-        assert_eq!(c64(4.0, 1.0) * c64(2.0, 3.0), c64(5.0, 14.0));
-        assert_eq!(c64(4.0, 1.0) * 2.0, c64(8.0, 2.0));
-        assert_eq!(2.0 * c64(4.0, 1.0), c64(8.0, 2.0));
+        assert_eq!(c64::new(4.0, 1.0) * c64::new(2.0, 3.0), c64::new(5.0, 14.0));
+        assert_eq!(c64::new(4.0, 1.0) * 2.0, c64::new(8.0, 2.0));
+        assert_eq!(2.0 * c64::new(4.0, 1.0), c64::new(8.0, 2.0));
+        assert_eq!(c64::new(4.0, 1.0) * &c64::new(2.0, 3.0), c64::new(5.0, 14.0));
+        assert_eq!(&c64::new(4.0, 1.0) * &c64::new(2.0, 3.0), c64::new(5.0, 14.0));
+    }
+
+    #[test]
+    fn mul_assign() {
+        let mut number = c64::new(4.0, 1.0);
+        number *= c64::new(2.0, 3.0);
+        assert_eq!(number, c64::new(5.0, 14.0));
+        number *= 2.0;
+        assert_eq!(number, c64::new(10.0, 28.0));
     }
 
     #[test]
     fn neg() {
-        assert_eq!(-c64(42.0, 69.0), c64(42.0, 69.0) * (-1.0));
+        assert_eq!(-c64::new(42.0, 69.0), c64::new(42.0, 69.0) * (-1.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn sub() {
-        assert_eq!(c64(4.0, 12.0) - c64(3.0, -15.0), c64(1.0, 27.0));
-        assert_eq!(c64(4.0, 12.0) - 3.0, c64(1.0, 12.0));
-        assert_eq!(3.0 - c64(4.0, 12.0), c64(-1.0, -12.0));
+        assert_eq!(c64::new(4.0, 12.0) - c64::new(3.0, -15.0), c64::new(1.0, 27.0));
+        assert_eq!(c64::new(4.0, 12.0) - 3.0, c64::new(1.0, 12.0));
+        assert_eq!(3.0 - c64::new(4.0, 12.0), c64::new(-1.0, -12.0));
+        assert_eq!(c64::new(4.0, 12.0) - &c64::new(3.0, -15.0), c64::new(1.0, 27.0));
+        assert_eq!(&c64::new(4.0, 12.0) - &c64::new(3.0, -15.0), c64::new(1.0, 27.0));
+    }
+
+    #[test]
+    fn sub_assign() {
+        let mut number = c64::new(4.0, 12.0);
+        number -= c64::new(3.0, -15.0);
+        assert_eq!(number, c64::new(1.0, 27.0));
+        number -= 1.0;
+        assert_eq!(number, c64::new(0.0, 27.0));
+    }
+
+    #[test]
+    fn sqrt() {
+        assert_eq!(c64::new(3.0, 4.0).sqrt(), c64::new(2.0, 1.0));
+        assert_eq!(c64::new(3.0, -4.0).sqrt(), c64::new(2.0, -1.0));
+    }
+
+    #[test]
+    fn exp() {
+        assert_eq!(c64::new(0.0, 0.0).exp(), c64::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn ln() {
+        assert_eq!(c64::new(1.0, 0.0).ln(), c64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn sin() {
+        assert_eq!(c64::new(0.0, 0.0).sin(), c64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn cos() {
+        assert_eq!(c64::new(0.0, 0.0).cos(), c64::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn asin() {
+        assert_eq!(c64::new(0.0, 0.0).asin(), c64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn acos() {
+        use std::f64::consts::PI;
+        assert_eq!(c64::new(0.0, 0.0).acos(), c64::new(PI / 2.0, 0.0));
+    }
+
+    #[test]
+    fn powi() {
+        assert_eq!(c64::new(2.0, 0.0).powi(3), c64::new(8.0, 0.0));
+        assert_eq!(c64::new(2.0, 0.0).powi(-1), c64::new(0.5, 0.0));
+        assert_eq!(c64::new(2.0, 0.0).powi(0), c64::new(1.0, 0.0));
+        assert_eq!(c64::new(1.0, 0.0).powi(i32::MIN), c64::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn powc() {
+        assert_eq!(c64::new(1.0, 0.0).powc(c64::new(2.0, 0.0)), c64::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn rem() {
+        assert_eq!(c64::new(7.0, 5.0) % c64::new(3.0, 0.0), c64::new(1.0, -1.0));
+        assert_eq!(c64::new(7.0, 5.0) % 3.0, c64::new(1.0, -1.0));
+        assert_eq!(7.0 % c64::new(3.0, 0.0), c64::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn from_str_radix() {
+        assert_eq!(c64::from_str_radix("1+2i", 10), Ok(c64::new(1.0, 2.0)));
+        assert_eq!(c64::from_str_radix("-3i", 10), Ok(c64::new(0.0, -3.0)));
+        assert_eq!(c64::from_str_radix("4", 10), Ok(c64::new(4.0, 0.0)));
+        assert_eq!(c64::from_str_radix("2-3i", 10), Ok(c64::new(2.0, -3.0)));
+        assert_eq!(c64::from_str_radix("i", 10), Ok(c64::new(0.0, 1.0)));
+        assert_eq!(c64::from_str_radix("-i", 10), Ok(c64::new(0.0, -1.0)));
+        assert_eq!(c64::from_str_radix("", 10), Err(ParseComplexError));
+    }
+
+    #[test]
+    fn norm_sqr() {
+        assert_eq!(c64::new(3.0, -4.0).norm_sqr(), 25.0);
+    }
+
+    #[test]
+    fn norm() {
+        assert_eq!(c64::new(3.0, -4.0).norm(), 5.0);
+    }
+
+    #[test]
+    fn arg() {
+        use std::f64::consts::PI;
+        assert_eq!(c64::new(0.0, 1.0).arg(), PI / 2.0);
+    }
+
+    #[test]
+    fn from_polar() {
+        use std::f64::consts::PI;
+        assert_eq!(c64::from_polar(2.0, PI), c64::new(-2.0, 2.0 * PI.sin()));
+    }
+
+    #[test]
+    fn to_polar() {
+        assert_eq!(c64::new(3.0, -4.0).to_polar(), (5.0, (-4.0f64).atan2(3.0)));
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(c64::zero(), c64::new(0.0, 0.0));
+        assert!(c64::zero().is_zero());
+        assert!(!c64::new(1.0, 0.0).is_zero());
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(c64::one(), c64::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn i() {
+        assert_eq!(c64::i(), c64::new(0.0, 1.0));
+        assert_eq!(c64::i() * c64::i(), -c64::one());
     }
 
     #[test]